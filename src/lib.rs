@@ -1,5 +1,73 @@
 //! Macros for serializing / deserializing enums containing no data variants using serde.
 
+/// Helpers used by the generated code of the `_rename_all` macros.
+///
+/// Not part of the public API; exposed only so the macros can reach it from a caller's crate.
+#[doc(hidden)]
+pub mod rename_all {
+    /// Splits an identifier such as `ContentFormat` into lowercase words (`["content",
+    /// "format"]`), the same way `serde(rename_all = "...")` segments a variant name.
+    ///
+    /// A new word starts at each uppercase letter that immediately follows a lowercase letter or
+    /// digit, so runs of uppercase letters (acronyms) are kept together.
+    fn split_words(ident: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        let mut current = String::new();
+        let mut prev_lower_or_digit = false;
+        for c in ident.chars() {
+            if c.is_uppercase() && prev_lower_or_digit {
+                words.push(current.to_lowercase());
+                current = String::new();
+            }
+            prev_lower_or_digit = c.is_lowercase() || c.is_numeric();
+            current.push(c);
+        }
+        if !current.is_empty() {
+            words.push(current.to_lowercase());
+        }
+        words
+    }
+
+    /// Converts a variant identifier to the given `rename_all` style.
+    ///
+    /// Supported styles: `"lowercase"`, `"UPPERCASE"`, `"snake_case"`, `"SCREAMING_SNAKE_CASE"`,
+    /// `"kebab-case"`, and `"camelCase"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `style` is not one of the supported styles above.
+    pub fn convert(ident: &str, style: &str) -> String {
+        let words = split_words(ident);
+        match style {
+            "lowercase" => words.concat(),
+            "UPPERCASE" => words.concat().to_uppercase(),
+            "snake_case" => words.join("_"),
+            "SCREAMING_SNAKE_CASE" => words.join("_").to_uppercase(),
+            "kebab-case" => words.join("-"),
+            "camelCase" => {
+                let mut result = String::new();
+                for (i, word) in words.iter().enumerate() {
+                    if i == 0 {
+                        result.push_str(word);
+                    } else {
+                        let mut chars = word.chars();
+                        if let Some(first) = chars.next() {
+                            result.extend(first.to_uppercase());
+                            result.push_str(chars.as_str());
+                        }
+                    }
+                }
+                result
+            }
+            other => panic!(
+                "`{}` is not a supported rename_all style; expected one of lowercase, \
+                 UPPERCASE, snake_case, SCREAMING_SNAKE_CASE, kebab-case, camelCase",
+                other
+            ),
+        }
+    }
+}
+
 /// Implement serde Serialize, Deserialize, and Visitor traits for the provided type and visitor
 /// type.
 #[macro_export]
@@ -162,6 +230,10 @@ macro_rules! serializable_enum {
 /// Generate `AsRef` and `FromStr` impls for the given type with the variant / string pairs
 /// specified.
 ///
+/// A variant may list extra accepted spellings with `|`, e.g. `Html => "html" | "htm" |
+/// "xhtml"`. `AsRef` always serializes to the first (canonical) string; `FromStr` accepts any of
+/// them, which lets the type keep parsing legacy spellings without a breaking change.
+///
 /// # Example
 ///
 /// ```
@@ -194,15 +266,48 @@ macro_rules! serializable_enum {
 ///     Color {
 ///         Red => "red",
 ///         Blue => "blue",
-///         Green => "green",
+///         Green => "green" | "grn",
+///     }
+///     Error::Parse
+/// }
+/// # } }
+/// ```
+///
+/// A trailing `.. => Other` clause turns the last, data-bearing variant into a catch-all: any
+/// string that doesn't match one of the listed variants deserializes into it instead of
+/// producing an error, which is useful for forward-compatible protocols where new variants may
+/// appear in serialized data before the code knows about them.
+///
+/// ```
+/// #[macro_use] extern crate serializable_enum;
+/// # fn main() { mod a {
+/// # #[derive(Debug)]
+/// # enum Error { Parse(String) }
+/// # impl ::std::fmt::Display for Error {
+/// #    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result { write!(f, "{:?}", self) }
+/// # }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Color {
+///     Red,
+///     Blue,
+///     Other(String),
+/// }
+///
+/// impl_as_ref_from_str! {
+///     Color {
+///         Red => "red",
+///         Blue => "blue",
+///         .. => Other,
 ///     }
 ///     Error::Parse
 /// }
 /// # } }
+/// ```
 #[macro_export]
 macro_rules! impl_as_ref_from_str {
     ($name:ident {
-        $($variant:ident => $str:expr,)+
+        $($variant:ident => $str:literal $(| $alias:literal)*,)+
     }
     $err:ident::$err_variant:ident
     ) => (
@@ -217,11 +322,304 @@ macro_rules! impl_as_ref_from_str {
             type Err = $err;
             fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
                 match s {
-                    $($str => Ok($name::$variant),)+
+                    $($str $(| $alias)* => Ok($name::$variant),)+
                     _ => Err($err::$err_variant(format!("`{}` is not a known `{}` variant", s, stringify!($name)))),
                 }
             }
 
         }
+    );
+    ($name:ident {
+        $($variant:ident => $str:literal $(| $alias:literal)*,)+
+        .. => $other:ident,
+    }
+    $err:ident::$err_variant:ident
+    ) => (
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                match *self {
+                    $($name::$variant=> $str,)+
+                    $name::$other(ref s) => &s[..],
+                }
+            }
+        }
+        impl ::std::str::FromStr for $name {
+            type Err = $err;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    $($str $(| $alias)* => Ok($name::$variant),)+
+                    other => Ok($name::$other(other.to_owned())),
+                }
+            }
+
+        }
+    )
+}
+
+/// Generate `AsRef` and `FromStr` impls for the given type, deriving the string form of each
+/// variant from its identifier instead of requiring an explicit mapping.
+///
+/// See [`rename_all::convert`] for the supported styles (`"lowercase"`, `"UPPERCASE"`,
+/// `"snake_case"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"`, `"camelCase"`). `AsRef` and
+/// `FromStr` use the identical conversion, so round-tripping through either is exact.
+///
+/// # Example
+///
+/// ```
+/// #[macro_use] extern crate serializable_enum;
+/// # fn main() { mod a {
+///
+/// // your error type
+/// #[derive(Debug)]
+/// enum Error {
+///     Parse(String),
+/// }
+///
+/// // You will need display implemented (you should already have this).
+/// impl ::std::fmt::Display for Error {
+///    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+///        write!(f, "{:?}", self)
+///    }
+/// }
+///
+/// enum ContentFormat {
+///     /// Markdown
+///     Markdown,
+///     /// Html
+///     Html,
+/// }
+///
+/// impl_as_ref_from_str_rename_all! {
+///     ContentFormat {
+///         Markdown,
+///         Html,
+///     }
+///     "kebab-case"
+///     Error::Parse
+/// }
+/// # } }
+/// ```
+#[macro_export]
+macro_rules! impl_as_ref_from_str_rename_all {
+    ($name:ident {
+        $($variant:ident,)+
+    }
+    $style:literal
+    $err:ident::$err_variant:ident
+    ) => (
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                match *self {
+                    $(
+                        $name::$variant => {
+                            static CACHE: ::std::sync::OnceLock<::std::string::String> =
+                                ::std::sync::OnceLock::new();
+                            CACHE.get_or_init(|| $crate::rename_all::convert(stringify!($variant), $style)).as_str()
+                        }
+                    )+
+                }
+            }
+        }
+        impl ::std::str::FromStr for $name {
+            type Err = $err;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                $(
+                    if s == $crate::rename_all::convert(stringify!($variant), $style) {
+                        return Ok($name::$variant);
+                    }
+                )+
+                Err($err::$err_variant(format!("`{}` is not a known `{}` variant", s, stringify!($name))))
+            }
+        }
+    )
+}
+
+/// Generate `as_u64` / `from_u64` inherent methods for the given type with the variant / integer
+/// discriminant pairs specified, for use with [`serde_u64_visitor!`] to serialize as a compact
+/// integer rather than a string.
+///
+/// # Example
+///
+/// ```
+/// #[macro_use] extern crate serializable_enum;
+/// # fn main() { mod a {
+///
+/// // your error type
+/// #[derive(Debug)]
+/// enum Error {
+///     Parse(String),
+/// }
+///
+/// // You will need display implemented (you should already have this).
+/// impl ::std::fmt::Display for Error {
+///    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+///        write!(f, "{:?}", self)
+///    }
+/// }
+///
+/// enum Color {
+///     Red,
+///     Blue,
+///     Green,
+/// }
+///
+/// impl_as_u64_from_u64! {
+///     Color {
+///         Red => 0,
+///         Blue => 1,
+///         Green => 2,
+///     }
+///     Error::Parse
+/// }
+/// # } }
+#[macro_export]
+macro_rules! impl_as_u64_from_u64 {
+    ($name:ident {
+        $($variant:ident => $num:expr,)+
+    }
+    $err:ident::$err_variant:ident
+    ) => (
+        impl $name {
+            /// Returns the integer discriminant assigned to this variant.
+            pub fn as_u64(&self) -> u64 {
+                match *self {
+                    $($name::$variant => $num,)+
+                }
+            }
+
+            /// Constructs the variant matching the given integer discriminant.
+            pub fn from_u64(n: u64) -> ::std::result::Result<Self, $err> {
+                match n {
+                    $($num => Ok($name::$variant),)+
+                    _ => Err($err::$err_variant(format!("`{}` is not a known `{}` discriminant", n, stringify!($name)))),
+                }
+            }
+        }
+    )
+}
+
+/// Implement serde `Serialize`, `Deserialize`, and `Visitor` traits for the provided type,
+/// serializing as an integer discriminant (via [`impl_as_u64_from_u64!`]) rather than a string.
+///
+/// The generated visitor implements both `visit_u64` and `visit_str`, so a type can be decoded
+/// from either a number or a string, as long as it also has a `FromStr` impl (e.g. via
+/// `impl_as_ref_from_str!`). Deserialization goes through `deserialize_any`, so this only works
+/// with self-describing formats (e.g. JSON, msgpack) whose `Deserializer` implements it; formats
+/// like bincode that require the expected type up front are not supported, and a value encoded
+/// with this macro's `Serialize` impl cannot be decoded back through them.
+#[macro_export]
+macro_rules! serde_u64_visitor {
+    ($name:ident, $visitor:ident, $($variant:ident),+) => (
+        impl ::serde::ser::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> where S: ::serde::Serializer {
+                serializer.serialize_u64(self.as_u64())
+            }
+        }
+
+        struct $visitor;
+        impl<'de> ::serde::de::Visitor<'de> for $visitor {
+            type Value = $name;
+
+            fn expecting(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str("a string or integer")
+            }
+
+            fn visit_u64<E>(self, n: u64) -> ::std::result::Result<Self::Value, E>
+            where E: ::serde::de::Error,
+            {
+                $name::from_u64(n).map_err(|e| ::serde::de::Error::custom(e.to_string()))
+            }
+
+            fn visit_i64<E>(self, n: i64) -> ::std::result::Result<Self::Value, E>
+            where E: ::serde::de::Error,
+            {
+                self.visit_u64(n as u64)
+            }
+
+            fn visit_str<E>(self, s: &str) -> ::std::result::Result<Self::Value, E>
+            where E: ::serde::de::Error,
+            {
+                #[allow(non_upper_case_globals)]
+                const VARIANTS: &'static [&'static str] = &[$(stringify!($variant)),+];
+
+                match s.trim().parse::<$name>() {
+                    Ok(t) => Ok(t),
+                    Err(e) => Err(::serde::de::Error::unknown_field(&e.to_string()[..], VARIANTS)),
+                }
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<$name, D::Error>
+                    where D: ::serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_any($visitor)
+                }
+        }
+    )
+}
+
+/// Generate a marker type implementing `serde_with`'s `SerializeAs<T>` / `DeserializeAs<'de, T>`
+/// for the given type, delegating to its existing `AsRef<str>` / `FromStr` impls.
+///
+/// This lets the string mapping be applied per-field via `#[serde_as(as = "...")]` (including
+/// inside containers like `Option<T>`, `Vec<T>`, or as a `HashMap` key) instead of through the
+/// type's own `Serialize`/`Deserialize` impls, which keeps those impls free to use a different
+/// representation (e.g. the [`impl_as_u64_from_u64!`] integer mode) for other callers.
+///
+/// Requires the `serde_with` feature and the `serde_with` crate as a dependency.
+///
+/// # Example
+///
+/// ```ignore
+/// impl_serde_as! {
+///     Color => ColorAsStr
+/// }
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Item {
+///     #[serde(with = "serde_with::As::<ColorAsStr>")]
+///     color: Color,
+/// }
+/// ```
+#[cfg(feature = "serde_with")]
+#[macro_export]
+macro_rules! impl_serde_as {
+    ($name:ident => $marker:ident) => (
+        /// `serde_with` adapter generated by `impl_serde_as!` for serializing / deserializing
+        #[doc = concat!("[`", stringify!($name), "`]")]
+        /// through a `#[serde_as(as = \"...\")]` field attribute.
+        pub struct $marker;
+
+        impl ::serde_with::SerializeAs<$name> for $marker {
+            fn serialize_as<S>(source: &$name, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where S: ::serde::Serializer,
+            {
+                serializer.serialize_str(source.as_ref())
+            }
+        }
+
+        impl<'de> ::serde_with::DeserializeAs<'de, $name> for $marker {
+            fn deserialize_as<D>(deserializer: D) -> ::std::result::Result<$name, D::Error>
+            where D: ::serde::Deserializer<'de>,
+            {
+                struct Helper;
+                impl<'de> ::serde::de::Visitor<'de> for Helper {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                        f.write_str("a str")
+                    }
+
+                    fn visit_str<E>(self, s: &str) -> ::std::result::Result<Self::Value, E>
+                    where E: ::serde::de::Error,
+                    {
+                        s.trim().parse::<$name>().map_err(|e| ::serde::de::Error::custom(e.to_string()))
+                    }
+                }
+
+                deserializer.deserialize_str(Helper)
+            }
+        }
     )
 }
@@ -17,6 +17,8 @@
 
 extern crate serde;
 extern crate serde_json;
+#[cfg(feature = "serde_with")]
+extern crate serde_with;
 #[macro_use]
 extern crate serializable_enum;
 
@@ -49,7 +51,7 @@ serializable_enum! {
 impl_as_ref_from_str! {
     ContentFormat {
         Markdown => "markdown",
-        Html => "html",
+        Html => "html" | "htm" | "xhtml",
     }
     Error::Parse
 }
@@ -92,6 +94,48 @@ impl_as_ref_from_str! {
     Error::Parse
 }
 
+serializable_enum! {
+    /// Colors, serialized via an automatic `kebab-case` conversion of the variant names.
+    #[derive(Debug, PartialEq)]
+    pub enum Color {
+        /// Red
+        Red,
+        /// Dark Blue
+        DarkBlue,
+    }
+    ColorVisitor
+}
+impl_as_ref_from_str_rename_all! {
+    Color {
+        Red,
+        DarkBlue,
+    }
+    "kebab-case"
+    Error::Parse
+}
+
+// `serializable_enum!` only accepts data-less variants, so the catch-all variant's enum is
+// declared by hand and wired up with `serde_visitor!` / `impl_as_ref_from_str!` directly.
+/// Recognized HTTP methods, with a catch-all for methods we don't yet know about.
+#[derive(Debug, PartialEq)]
+pub enum Method {
+    /// Get
+    Get,
+    /// Post
+    Post,
+    /// An unrecognized method, carrying the raw string that was seen.
+    Other(String),
+}
+serde_visitor!(Method, MethodVisitor, Get, Post);
+impl_as_ref_from_str! {
+    Method {
+        Get => "GET",
+        Post => "POST",
+        .. => Other,
+    }
+    Error::Parse
+}
+
 #[test]
 fn test_pub_serialization() {
     let md = ContentFormat::Markdown;
@@ -109,3 +153,96 @@ fn test_priv_serialization() {
     let des_md: PrivContentFormat = serde_json::from_str("\"markdown\"").unwrap();
     assert_eq!(md, des_md);
 }
+
+#[test]
+fn test_alias_deserialization() {
+    let html = ContentFormat::Html;
+    assert_eq!(serde_json::to_string(&html).unwrap(), "\"html\"");
+
+    let des_htm: ContentFormat = serde_json::from_str("\"htm\"").unwrap();
+    assert_eq!(html, des_htm);
+
+    let des_xhtml: ContentFormat = serde_json::from_str("\"xhtml\"").unwrap();
+    assert_eq!(html, des_xhtml);
+}
+
+/// A priority, serialized as a compact integer discriminant but still parseable from a string.
+#[derive(Debug, PartialEq)]
+pub enum Priority {
+    /// Low
+    Low,
+    /// High
+    High,
+}
+impl_as_ref_from_str! {
+    Priority {
+        Low => "low",
+        High => "high",
+    }
+    Error::Parse
+}
+impl_as_u64_from_u64! {
+    Priority {
+        Low => 0,
+        High => 1,
+    }
+    Error::Parse
+}
+serde_u64_visitor!(Priority, PriorityVisitor, Low, High);
+
+#[test]
+fn test_catch_all_deserialization() {
+    let get = Method::Get;
+    assert_eq!(serde_json::to_string(&get).unwrap(), "\"GET\"");
+
+    let des_get: Method = serde_json::from_str("\"GET\"").unwrap();
+    assert_eq!(get, des_get);
+
+    let patch: Method = serde_json::from_str("\"PATCH\"").unwrap();
+    assert_eq!(patch, Method::Other("PATCH".to_owned()));
+    assert_eq!(serde_json::to_string(&patch).unwrap(), "\"PATCH\"");
+}
+
+#[cfg(feature = "serde_with")]
+impl_serde_as! {
+    ContentFormat => ContentFormatAsStr
+}
+
+#[cfg(feature = "serde_with")]
+#[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+struct Document {
+    #[serde(with = "::serde_with::As::<ContentFormatAsStr>")]
+    format: ContentFormat,
+}
+
+#[cfg(feature = "serde_with")]
+#[test]
+fn test_serde_as_serialization() {
+    let doc = Document { format: ContentFormat::Html };
+    assert_eq!(serde_json::to_string(&doc).unwrap(), "{\"format\":\"html\"}");
+
+    let des_doc: Document = serde_json::from_str("{\"format\":\"html\"}").unwrap();
+    assert_eq!(doc, des_doc);
+}
+
+#[test]
+fn test_u64_serialization() {
+    let high = Priority::High;
+    assert_eq!(serde_json::to_string(&high).unwrap(), "1");
+
+    let des_high: Priority = serde_json::from_str("1").unwrap();
+    assert_eq!(high, des_high);
+
+    // still parseable from its string form, since `FromStr` is implemented separately.
+    let des_from_str: Priority = serde_json::from_str("\"high\"").unwrap();
+    assert_eq!(high, des_from_str);
+}
+
+#[test]
+fn test_rename_all_serialization() {
+    let blue = Color::DarkBlue;
+    assert_eq!(serde_json::to_string(&blue).unwrap(), "\"dark-blue\"");
+
+    let des_blue: Color = serde_json::from_str("\"dark-blue\"").unwrap();
+    assert_eq!(blue, des_blue);
+}